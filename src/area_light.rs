@@ -0,0 +1,112 @@
+use crate::{color::{Color, Colors}, point_light::PointLight, tuple::Tuple};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AreaLight {
+    corner: Tuple,
+    u_vec: Tuple,
+    u_steps: usize,
+    v_vec: Tuple,
+    v_steps: usize,
+    intensity: Color,
+}
+
+impl AreaLight {
+    pub fn new(
+        corner: Tuple,
+        full_u_vec: Tuple,
+        u_steps: usize,
+        full_v_vec: Tuple,
+        v_steps: usize,
+        intensity: Color,
+    ) -> Self {
+        Self {
+            corner,
+            u_vec: full_u_vec * (1.0 / u_steps as f64),
+            u_steps,
+            v_vec: full_v_vec * (1.0 / v_steps as f64),
+            v_steps,
+            intensity,
+        }
+    }
+
+    pub fn corner(&self) -> Tuple {
+        self.corner
+    }
+
+    pub fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    pub fn samples(&self) -> usize {
+        self.u_steps * self.v_steps
+    }
+
+    pub fn position(&self) -> Tuple {
+        self.corner + self.u_vec * (self.u_steps as f64 / 2.0) + self.v_vec * (self.v_steps as f64 / 2.0)
+    }
+
+    pub fn as_point_light(&self) -> PointLight {
+        PointLight::new(self.position(), self.intensity)
+    }
+
+    pub fn sample_positions(&self) -> Vec<Tuple> {
+        let single_sample = self.samples() <= 1;
+        let mut positions = Vec::with_capacity(self.samples());
+
+        for v in 0..self.v_steps {
+            for u in 0..self.u_steps {
+                let (jitter_u, jitter_v) = if single_sample {
+                    (0.5, 0.5)
+                } else {
+                    (jitter(u, v, 0), jitter(u, v, 1))
+                };
+
+                positions.push(
+                    self.corner
+                        + self.u_vec * (u as f64 + jitter_u)
+                        + self.v_vec * (v as f64 + jitter_v),
+                );
+            }
+        }
+
+        positions
+    }
+}
+
+fn jitter(u: usize, v: usize, salt: u64) -> f64 {
+    let mut x = (u as u64)
+        .wrapping_mul(374761393)
+        .wrapping_add((v as u64).wrapping_mul(668265263))
+        .wrapping_add(salt.wrapping_mul(2246822519));
+    x = (x ^ (x >> 13)).wrapping_mul(1274126177);
+    x ^= x >> 16;
+    (x % 1_000_000) as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creating_an_area_light() {
+        let corner = Tuple::point(0.0, 0.0, 0.0);
+        let v1 = Tuple::vector(2.0, 0.0, 0.0);
+        let v2 = Tuple::vector(0.0, 0.0, 1.0);
+
+        let light = AreaLight::new(corner, v1, 4, v2, 2, Colors::White.into());
+
+        assert_eq!(corner, light.corner());
+        assert_eq!(8, light.samples());
+    }
+
+    #[test]
+    fn a_single_sample_area_light_samples_its_own_center() {
+        let corner = Tuple::point(0.0, 0.0, 0.0);
+        let v1 = Tuple::vector(2.0, 0.0, 0.0);
+        let v2 = Tuple::vector(0.0, 0.0, 1.0);
+
+        let light = AreaLight::new(corner, v1, 1, v2, 1, Colors::White.into());
+
+        assert_eq!(vec![light.position()], light.sample_positions());
+    }
+}
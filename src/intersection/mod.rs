@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+pub mod ray;
+
+use crate::shape::Shape;
+
+#[derive(Debug, Clone)]
+pub struct Intersection {
+    t: f64,
+    object: Arc<dyn Shape + Send + Sync>,
+}
+
+impl Intersection {
+    pub fn new(t: f64, object: Arc<dyn Shape + Send + Sync>) -> Self {
+        Self { t, object }
+    }
+
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    pub fn object(&self) -> &Arc<dyn Shape + Send + Sync> {
+        &self.object
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct IntersectionHeap {
+    intersections: Vec<Intersection>,
+}
+
+impl IntersectionHeap {
+    pub fn new() -> Self {
+        Self {
+            intersections: vec![],
+        }
+    }
+
+    pub fn push(&mut self, intersection: Intersection) {
+        let position = self
+            .intersections
+            .partition_point(|i| i.t() < intersection.t());
+        self.intersections.insert(position, intersection);
+    }
+
+    pub fn len(&self) -> usize {
+        self.intersections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intersections.is_empty()
+    }
+
+    pub fn hit(&self) -> Option<Intersection> {
+        self.intersections.iter().find(|i| i.t() >= 0.0).cloned()
+    }
+}
+
+impl IntoIterator for IntersectionHeap {
+    type Item = Intersection;
+    type IntoIter = std::vec::IntoIter<Intersection>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intersections.into_iter()
+    }
+}
+
+impl std::ops::Index<usize> for IntersectionHeap {
+    type Output = Intersection;
+
+    fn index(&self, index: usize) -> &Intersection {
+        &self.intersections[index]
+    }
+}
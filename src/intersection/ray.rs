@@ -1,6 +1,6 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
-use crate::{shape::Shape, tuple::Tuple};
+use crate::{shape::Shape, transformation::Transformation, tuple::Tuple};
 
 use super::{Intersection, IntersectionHeap};
 
@@ -27,7 +27,11 @@ impl Ray {
         self.origin + (self.direction * position)
     }
 
-    pub fn intersections(&self, shape: Rc<dyn Shape>) -> IntersectionHeap {
+    pub fn transform(&self, transformation: Transformation) -> Ray {
+        Ray::new(transformation * self.origin, transformation * self.direction)
+    }
+
+    pub fn intersections(&self, shape: Arc<dyn Shape + Send + Sync>) -> IntersectionHeap {
         let mut heap = IntersectionHeap::new();
         for i in shape
             .intersects(*self)
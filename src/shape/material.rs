@@ -7,6 +7,7 @@ pub struct Material {
     diffuse: f64,
     specular: f64,
     shininess: f64,
+    reflective: f64,
 }
 
 impl Material {
@@ -34,6 +35,10 @@ impl Material {
         self.shininess
     }
 
+    pub fn reflective(&self) -> f64 {
+        self.reflective
+    }
+
     pub fn with_color(mut self, color: Color) -> Self {
         self.color = color;
         self
@@ -59,6 +64,11 @@ impl Material {
         self
     }
 
+    pub fn with_reflective(mut self, reflective: f64) -> Self {
+        self.reflective = reflective;
+        self
+    }
+
     /**
        Combine the surface color with the light's color / intensity.
 
@@ -86,6 +96,7 @@ impl Material {
         point: Tuple,
         eye_v: Tuple,
         normal_v: Tuple,
+        in_shadow: bool,
     ) -> Color {
         let effective_color = self.color() * light.intensity();
 
@@ -95,7 +106,7 @@ impl Material {
 
         let light_dot_normal = light_v * normal_v;
 
-        let (diffuse, specular) = if light_dot_normal < 0.0 {
+        let (diffuse, specular) = if in_shadow || light_dot_normal < 0.0 {
             (Colors::Black.into(), Colors::Black.into())
         } else {
             let diffuse = effective_color * self.diffuse() * light_dot_normal;
@@ -123,6 +134,7 @@ impl Default for Material {
             diffuse: 0.9,
             specular: 0.9,
             shininess: 200.0,
+            reflective: 0.0,
         }
     }
 }
@@ -134,6 +146,7 @@ impl PartialEq for Material {
             && eq_f64(self.diffuse, other.diffuse)
             && eq_f64(self.specular, other.specular)
             && eq_f64(self.shininess, other.shininess)
+            && eq_f64(self.reflective, other.reflective)
     }
 }
 
@@ -150,6 +163,7 @@ mod tests {
         assert_eq!(0.9, m.diffuse());
         assert_eq!(0.9, m.specular());
         assert_eq!(200.0, m.shininess());
+        assert_eq!(0.0, m.reflective());
     }
 
     #[test]
@@ -161,7 +175,7 @@ mod tests {
         let normal_v = Tuple::vector(0.0, 0.0, -1.0);
         let light = PointLight::new(Tuple::point(0.0, 0.0, -10.0), Colors::White.into());
 
-        let result = m.lighting(light, position, eye_v, normal_v);
+        let result = m.lighting(light, position, eye_v, normal_v, false);
 
         assert_eq!(Color::new(1.9, 1.9, 1.9), result);
     }
@@ -175,7 +189,7 @@ mod tests {
         let normal_v = Tuple::vector(0.0, 0.0, -1.0);
         let light = PointLight::new(Tuple::point(0.0, 0.0, -10.0), Colors::White.into());
 
-        let result = m.lighting(light, position, eye_v, normal_v);
+        let result = m.lighting(light, position, eye_v, normal_v, false);
 
         assert_eq!(Color::new(1.0, 1.0, 1.0), result);
     }
@@ -189,7 +203,7 @@ mod tests {
         let normal_v = Tuple::vector(0.0, 0.0, -1.0);
         let light = PointLight::new(Tuple::point(0.0, 10.0, -10.0), Colors::White.into());
 
-        let result = m.lighting(light, position, eye_v, normal_v);
+        let result = m.lighting(light, position, eye_v, normal_v, false);
 
         assert_eq!(Color::new(0.7364, 0.7364, 0.7364), result);
     }
@@ -203,7 +217,21 @@ mod tests {
         let normal_v = Tuple::vector(0.0, 0.0, -1.0);
         let light = PointLight::new(Tuple::point(0.0, 0.0, 10.0), Colors::White.into());
 
-        let result = m.lighting(light, position, eye_v, normal_v);
+        let result = m.lighting(light, position, eye_v, normal_v, false);
+
+        assert_eq!(Color::new(0.1, 0.1, 0.1), result);
+    }
+
+    #[test]
+    fn lighting_with_the_surface_in_shadow() {
+        let m = Material::new();
+        let position = Tuple::origin();
+
+        let eye_v = Tuple::vector(0.0, 0.0, -1.0);
+        let normal_v = Tuple::vector(0.0, 0.0, -1.0);
+        let light = PointLight::new(Tuple::point(0.0, 0.0, -10.0), Colors::White.into());
+
+        let result = m.lighting(light, position, eye_v, normal_v, true);
 
         assert_eq!(Color::new(0.1, 0.1, 0.1), result);
     }
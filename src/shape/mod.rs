@@ -0,0 +1,22 @@
+use std::fmt::Debug;
+
+use crate::{
+    intersection::ray::Ray, shape::material::Material, transformation::Transformation, tuple::Tuple,
+};
+
+pub mod material;
+pub mod sphere;
+
+pub trait Shape: Debug + Send + Sync {
+    fn material(&self) -> Material;
+
+    fn set_material(&mut self, material: Material);
+
+    fn transformation(&self) -> Transformation;
+
+    fn set_transformation(&mut self, transformation: Transformation);
+
+    fn intersects(&self, ray: Ray) -> Vec<f64>;
+
+    fn bounding_box(&self) -> (Tuple, Tuple);
+}
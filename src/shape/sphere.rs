@@ -0,0 +1,89 @@
+use crate::{intersection::ray::Ray, shape::material::Material, transformation::Transformation, tuple::Tuple};
+
+use super::Shape;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sphere {
+    material: Material,
+    transformation: Transformation,
+}
+
+impl Sphere {
+    pub fn new() -> Self {
+        Self {
+            material: Material::default(),
+            transformation: Transformation::identity(),
+        }
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shape for Sphere {
+    fn material(&self) -> Material {
+        self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn transformation(&self) -> Transformation {
+        self.transformation
+    }
+
+    fn set_transformation(&mut self, transformation: Transformation) {
+        self.transformation = transformation;
+    }
+
+    fn intersects(&self, ray: Ray) -> Vec<f64> {
+        let local_ray = ray.transform(self.transformation.inverse());
+
+        let sphere_to_ray = local_ray.origin() - Tuple::point(0.0, 0.0, 0.0);
+
+        let a = local_ray.direction().dot(local_ray.direction());
+        let b = 2.0 * local_ray.direction().dot(sphere_to_ray);
+        let c = sphere_to_ray.dot(sphere_to_ray) - 1.0;
+
+        let discriminant = b * b - 4.0 * a * c;
+
+        if discriminant < 0.0 {
+            return vec![];
+        }
+
+        let sqrt_discriminant = discriminant.sqrt();
+
+        vec![
+            (-b - sqrt_discriminant) / (2.0 * a),
+            (-b + sqrt_discriminant) / (2.0 * a),
+        ]
+    }
+
+    fn bounding_box(&self) -> (Tuple, Tuple) {
+        let corners = [-1.0, 1.0].iter().flat_map(|&x| {
+            [-1.0, 1.0].iter().flat_map(move |&y| {
+                [-1.0, 1.0]
+                    .iter()
+                    .map(move |&z| self.transformation * Tuple::point(x, y, z))
+                    .collect::<Vec<_>>()
+            })
+        });
+
+        corners.fold(
+            (
+                Tuple::point(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+                Tuple::point(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+            ),
+            |(min, max), p| {
+                (
+                    Tuple::point(min.x().min(p.x()), min.y().min(p.y()), min.z().min(p.z())),
+                    Tuple::point(max.x().max(p.x()), max.y().max(p.y()), max.z().max(p.z())),
+                )
+            },
+        )
+    }
+}
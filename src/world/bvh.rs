@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use crate::{intersection::ray::Ray, shape::Shape, tuple::Tuple};
+
+const LEAF_SIZE: usize = 4;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Aabb {
+    min: Tuple,
+    max: Tuple,
+}
+
+impl Aabb {
+    pub fn new(min: Tuple, max: Tuple) -> Self {
+        Self { min, max }
+    }
+
+    pub fn min(&self) -> Tuple {
+        self.min
+    }
+
+    pub fn max(&self) -> Tuple {
+        self.max
+    }
+
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb::new(
+            Tuple::point(
+                self.min.x().min(other.min.x()),
+                self.min.y().min(other.min.y()),
+                self.min.z().min(other.min.z()),
+            ),
+            Tuple::point(
+                self.max.x().max(other.max.x()),
+                self.max.y().max(other.max.y()),
+                self.max.z().max(other.max.z()),
+            ),
+        )
+    }
+
+    pub fn centroid(&self) -> Tuple {
+        Tuple::point(
+            (self.min.x() + self.max.x()) / 2.0,
+            (self.min.y() + self.max.y()) / 2.0,
+            (self.min.z() + self.max.z()) / 2.0,
+        )
+    }
+
+    pub fn longest_axis(&self) -> usize {
+        let size = self.max - self.min;
+        let (x, y, z) = (size.x().abs(), size.y().abs(), size.z().abs());
+
+        if x >= y && x >= z {
+            0
+        } else if y >= z {
+            1
+        } else {
+            2
+        }
+    }
+
+    pub fn is_hit_by(&self, ray: Ray) -> bool {
+        let (mut tmin, mut tmax) = (f64::NEG_INFINITY, f64::INFINITY);
+
+        for axis in 0..3 {
+            let (origin, direction, min, max) = match axis {
+                0 => (ray.origin().x(), ray.direction().x(), self.min.x(), self.max.x()),
+                1 => (ray.origin().y(), ray.direction().y(), self.min.y(), self.max.y()),
+                _ => (ray.origin().z(), ray.direction().z(), self.min.z(), self.max.z()),
+            };
+
+            if direction == 0.0 {
+                if origin < min || origin > max {
+                    return false;
+                }
+                continue;
+            }
+
+            let mut t1 = (min - origin) / direction;
+            let mut t2 = (max - origin) / direction;
+
+            if t1 > t2 {
+                std::mem::swap(&mut t1, &mut t2);
+            }
+
+            tmin = tmin.max(t1);
+            tmax = tmax.min(t2);
+
+            if tmin > tmax {
+                return false;
+            }
+        }
+
+        tmax >= 0.0
+    }
+}
+
+fn bounds_of(shape: &Arc<dyn Shape + Send + Sync>) -> Aabb {
+    let (min, max) = shape.bounding_box();
+    Aabb::new(min, max)
+}
+
+#[derive(Debug)]
+enum BvhNode {
+    Leaf {
+        bounds: Aabb,
+        shapes: Vec<Arc<dyn Shape + Send + Sync>>,
+    },
+    Branch {
+        bounds: Aabb,
+        left: Box<BvhNode>,
+        right: Box<BvhNode>,
+    },
+}
+
+impl BvhNode {
+    fn bounds(&self) -> Aabb {
+        match self {
+            BvhNode::Leaf { bounds, .. } => *bounds,
+            BvhNode::Branch { bounds, .. } => *bounds,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Bvh {
+    root: BvhNode,
+}
+
+impl Bvh {
+    pub fn build(shapes: Vec<Arc<dyn Shape + Send + Sync>>) -> Self {
+        Self {
+            root: Self::build_node(shapes),
+        }
+    }
+
+    fn build_node(mut shapes: Vec<Arc<dyn Shape + Send + Sync>>) -> BvhNode {
+        let bounds = shapes
+            .iter()
+            .map(|s| bounds_of(s))
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_else(|| Aabb::new(Tuple::origin(), Tuple::origin()));
+
+        if shapes.len() <= LEAF_SIZE {
+            return BvhNode::Leaf { bounds, shapes };
+        }
+
+        let axis = bounds.longest_axis();
+        shapes.sort_by(|a, b| {
+            let ca = bounds_of(a).centroid();
+            let cb = bounds_of(b).centroid();
+            let (a, b) = match axis {
+                0 => (ca.x(), cb.x()),
+                1 => (ca.y(), cb.y()),
+                _ => (ca.z(), cb.z()),
+            };
+            a.partial_cmp(&b).unwrap()
+        });
+
+        let mid = shapes.len() / 2;
+        let right_shapes = shapes.split_off(mid);
+        let left_shapes = shapes;
+
+        BvhNode::Branch {
+            bounds,
+            left: Box::new(Self::build_node(left_shapes)),
+            right: Box::new(Self::build_node(right_shapes)),
+        }
+    }
+
+    pub fn candidates(&self, ray: Ray) -> Vec<Arc<dyn Shape + Send + Sync>> {
+        let mut found = vec![];
+        Self::collect(&self.root, ray, &mut found);
+        found
+    }
+
+    fn collect(node: &BvhNode, ray: Ray, found: &mut Vec<Arc<dyn Shape + Send + Sync>>) {
+        if !node.bounds().is_hit_by(ray) {
+            return;
+        }
+
+        match node {
+            BvhNode::Leaf { shapes, .. } => found.extend(shapes.iter().cloned()),
+            BvhNode::Branch { left, right, .. } => {
+                Self::collect(left, ray, found);
+                Self::collect(right, ray, found);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_ray_misses_a_box() {
+        let aabb = Aabb::new(Tuple::point(-1.0, -1.0, -1.0), Tuple::point(1.0, 1.0, 1.0));
+        let r = Ray::new(Tuple::point(2.0, 2.0, 2.0), Tuple::vector(0.0, 0.0, 1.0));
+
+        assert!(!aabb.is_hit_by(r));
+    }
+
+    #[test]
+    fn a_ray_hits_a_box() {
+        let aabb = Aabb::new(Tuple::point(-1.0, -1.0, -1.0), Tuple::point(1.0, 1.0, 1.0));
+        let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 0.0, 1.0));
+
+        assert!(aabb.is_hit_by(r));
+    }
+
+    #[test]
+    fn an_axis_parallel_ray_does_not_produce_nan() {
+        let aabb = Aabb::new(Tuple::point(-1.0, -1.0, -1.0), Tuple::point(1.0, 1.0, 1.0));
+        let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 1.0, 0.0));
+
+        assert!(!aabb.is_hit_by(r));
+    }
+}
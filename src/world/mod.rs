@@ -1,6 +1,10 @@
-use std::{rc::Rc, vec};
+use std::{f64::consts::PI, sync::Arc, vec};
+
+use rand::Rng;
+use rayon::prelude::*;
 
 use crate::{
+    area_light::AreaLight,
     color::{Color, Colors},
     intersection::{
         precomputation::PreComputations,
@@ -13,103 +17,257 @@ use crate::{
     tuple::Tuple,
 };
 
+mod bvh;
+
+use bvh::Bvh;
+
+const MIN_PATH_BOUNCES: usize = 3;
+
+const MAX_PATH_DEPTH: usize = 50;
+
+const ROULETTE_SURVIVAL: f64 = 0.9;
+
+pub const DEFAULT_REMAINING: usize = 5;
+
 #[derive(Debug)]
 pub struct World {
-    shapes: Vec<Rc<dyn Shape>>,
-    light: Option<PointLight>,
+    shapes: Vec<Arc<dyn Shape + Send + Sync>>,
+    lights: Vec<PointLight>,
+    area_lights: Vec<AreaLight>,
+    bvh: Option<Bvh>,
 }
 
 impl World {
     pub fn new() -> Self {
         Self {
             shapes: vec![],
-            light: None,
+            lights: vec![],
+            area_lights: vec![],
+            bvh: None,
         }
     }
 
-    pub fn shapes(&self) -> &Vec<Rc<dyn Shape>> {
+    pub fn build_bvh(&mut self) {
+        self.bvh = Some(Bvh::build(self.shapes.clone()));
+    }
+
+    pub fn shapes(&self) -> &Vec<Arc<dyn Shape + Send + Sync>> {
         &self.shapes
     }
 
     pub fn add_shape<T: Shape + 'static>(&mut self, shape: T) {
-        self.shapes.push(Rc::new(shape));
+        self.shapes.push(Arc::new(shape));
     }
 
-    pub fn shapes_mut(&mut self) -> &mut Vec<Rc<dyn Shape>> {
+    pub fn shapes_mut(&mut self) -> &mut Vec<Arc<dyn Shape + Send + Sync>> {
         &mut self.shapes
     }
 
-    pub fn light(&self) -> &Option<PointLight> {
-        &self.light
+    pub fn lights(&self) -> &Vec<PointLight> {
+        &self.lights
+    }
+
+    pub fn add_light(&mut self, point_light: PointLight) -> &Self {
+        self.lights.push(point_light);
+        self
     }
 
     pub fn set_light(&mut self, point_light: PointLight) -> &Self {
-        self.light = Some(point_light);
+        self.lights = vec![point_light];
+        self
+    }
+
+    pub fn area_lights(&self) -> &Vec<AreaLight> {
+        &self.area_lights
+    }
+
+    pub fn add_area_light(&mut self, area_light: AreaLight) -> &Self {
+        self.area_lights.push(area_light);
         self
     }
 
     pub fn intersects(&self, r: Ray) -> IntersectionHeap {
         let mut heap = IntersectionHeap::new();
 
-        for s in self.shapes() {
-            let intersections = r.intersections(s.clone());
-            for i in intersections {
-                heap.push(i);
+        match &self.bvh {
+            Some(bvh) => {
+                for s in bvh.candidates(r) {
+                    for i in r.intersections(s) {
+                        heap.push(i);
+                    }
+                }
+            }
+            None => {
+                for s in self.shapes.iter() {
+                    for i in r.intersections(s.clone()) {
+                        heap.push(i);
+                    }
+                }
             }
         }
 
         heap
     }
 
-    pub fn shade_hit(&self, comps: &PreComputations) -> Color {
-        let shadowed = self.is_shadowed(comps.over_point());
-
-        if let Some(light) = self.light {
-            comps.object().material().lighting(
-                comps.object().as_ref(),
-                light,
+    pub fn shade_hit(&self, comps: &PreComputations, remaining: usize) -> Color {
+        let point_light_surface = self.lights.iter().fold(Colors::Black.into(), |color, light| {
+            let shadowed = self.is_shadowed(comps.over_point(), light);
+
+            color
+                + comps.object().material().lighting(
+                    *light,
+                    comps.over_point(),
+                    comps.eye_v(),
+                    comps.normal_v(),
+                    shadowed,
+                )
+        });
+
+        let area_light_surface = self.area_lights.iter().fold(Colors::Black.into(), |color, light| {
+            let intensity = self.intensity_at(comps.over_point(), light);
+            let point_light = light.as_point_light();
+            let material = comps.object().material();
+
+            let ambient = material.color() * point_light.intensity() * material.ambient();
+            let fully_lit = material.lighting(
+                point_light,
                 comps.over_point(),
                 comps.eye_v(),
                 comps.normal_v(),
-                shadowed,
-            )
-        } else {
-            Colors::Black.into()
+                false,
+            );
+
+            color + ambient + (fully_lit - ambient) * intensity
+        });
+
+        point_light_surface + area_light_surface + self.reflected_color(comps, remaining)
+    }
+
+    pub fn intensity_at(&self, point: Tuple, light: &AreaLight) -> f64 {
+        let samples = light.sample_positions();
+
+        let unoccluded = samples
+            .iter()
+            .filter(|&&sample| !self.is_shadowed(point, &PointLight::new(sample, light.intensity())))
+            .count();
+
+        unoccluded as f64 / samples.len() as f64
+    }
+
+    pub fn reflected_color(&self, comps: &PreComputations, remaining: usize) -> Color {
+        let reflective = comps.object().material().reflective();
+
+        if remaining == 0 || reflective == 0.0 {
+            return Colors::Black.into();
         }
+
+        let reflect_ray = Ray::new(comps.over_point(), comps.reflect_v());
+        let color = self.color_at(reflect_ray, remaining - 1);
+
+        color * reflective
     }
 
-    pub fn color_at(&self, ray: Ray) -> Color {
+    pub fn color_at(&self, ray: Ray, remaining: usize) -> Color {
         let mut intersections = self.intersects(ray);
 
         if let Some(hit) = intersections.hit() {
             let comps = PreComputations::new(hit, ray.clone());
-            self.shade_hit(&comps)
+            self.shade_hit(&comps, remaining)
         } else {
             Colors::Black.into()
         }
     }
 
-    pub fn is_shadowed(&self, point: Tuple) -> bool {
-        if let Some(l) = self.light {
-            let v = l.position() - point;
+    pub fn path_color(&self, ray: Ray, depth: usize, rng: &mut impl Rng) -> Color {
+        if depth >= MAX_PATH_DEPTH {
+            return Colors::Black.into();
+        }
 
-            let distance = v.magnitude();
-            let direction = v.normalize();
+        let mut intersections = self.intersects(ray);
 
-            let r = Ray::new(point, direction);
+        let hit = match intersections.hit() {
+            Some(hit) => hit,
+            None => return Colors::Black.into(),
+        };
 
-            let h = self.intersects(r).hit();
+        let comps = PreComputations::new(hit, ray.clone());
+        let direct = self.shade_hit(&comps, 0);
 
-            match h {
-                Some(h) if h.t() < distance => true,
-                _ => false,
-            }
+        let survival_probability = if depth < MIN_PATH_BOUNCES {
+            1.0
         } else {
-            false
+            ROULETTE_SURVIVAL
+        };
+
+        if rng.gen::<f64>() > survival_probability {
+            return direct;
+        }
+
+        let bounce_ray = Ray::new(comps.over_point(), cosine_sample_hemisphere(comps.normal_v(), rng));
+        let indirect = self.path_color(bounce_ray, depth + 1, rng) * comps.object().material().color()
+            / survival_probability;
+
+        direct + indirect
+    }
+
+    pub fn render_path_traced(&self, ray: Ray, samples: usize, rng: &mut impl Rng) -> Color {
+        let total: Color = (0..samples).fold(Colors::Black.into(), |color, _| {
+            color + self.path_color(ray, 0, rng)
+        });
+
+        total * (1.0 / samples as f64)
+    }
+
+    pub fn render<F>(&self, width: usize, height: usize, ray_for_pixel: F, set_pixel: impl Fn(usize, usize, Color) + Sync)
+    where
+        F: Fn(usize, usize) -> Ray + Sync,
+    {
+        (0..height).into_par_iter().for_each(|y| {
+            for x in 0..width {
+                let ray = ray_for_pixel(x, y);
+                let color = self.color_at(ray, DEFAULT_REMAINING);
+                set_pixel(x, y, color);
+            }
+        });
+    }
+
+    pub fn is_shadowed(&self, point: Tuple, light: &PointLight) -> bool {
+        let v = light.position() - point;
+
+        let distance = v.magnitude();
+        let direction = v.normalize();
+
+        let r = Ray::new(point, direction);
+
+        let h = self.intersects(r).hit();
+
+        match h {
+            Some(h) if h.t() < distance => true,
+            _ => false,
         }
     }
 }
 
+fn cosine_sample_hemisphere(normal: Tuple, rng: &mut impl Rng) -> Tuple {
+    let u1: f64 = rng.gen();
+    let u2: f64 = rng.gen();
+
+    let r = u1.sqrt();
+    let theta = 2.0 * PI * u2;
+
+    let local = Tuple::vector(r * theta.cos(), r * theta.sin(), (1.0 - u1).sqrt());
+
+    let up = if normal.x().abs() > 0.9 {
+        Tuple::vector(0.0, 1.0, 0.0)
+    } else {
+        Tuple::vector(1.0, 0.0, 0.0)
+    };
+    let tangent = up.cross(normal).normalize();
+    let bitangent = normal.cross(tangent);
+
+    (tangent * local.x() + bitangent * local.y() + normal * local.z()).normalize()
+}
+
 impl Default for World {
     fn default() -> Self {
         let mut s2 = Sphere::new();
@@ -126,8 +284,10 @@ impl Default for World {
 
         let light = PointLight::new(Tuple::point(-10.0, 10.0, -10.0), Colors::White.into());
         Self {
-            shapes: vec![Rc::new(s1), Rc::new(s2)],
-            light: Some(light),
+            shapes: vec![Arc::new(s1), Arc::new(s2)],
+            lights: vec![light],
+            area_lights: vec![],
+            bvh: None,
         }
     }
 }
@@ -135,6 +295,7 @@ impl Default for World {
 #[cfg(test)]
 mod tests {
     use crate::intersection::Intersection;
+    use rand::SeedableRng;
 
     use super::*;
 
@@ -143,7 +304,7 @@ mod tests {
         let w = World::new();
 
         assert_eq!(0, w.shapes().len());
-        assert_eq!(&None, w.light());
+        assert!(w.lights().is_empty());
     }
 
     #[test]
@@ -159,9 +320,9 @@ mod tests {
 
         let world = World::default();
 
-        assert!(world.light.is_some());
+        assert_eq!(1, world.lights().len());
 
-        assert_eq!(light, world.light().unwrap());
+        assert_eq!(light, world.lights()[0]);
         assert!(world
             .shapes()
             .iter()
@@ -183,6 +344,21 @@ mod tests {
         assert_eq!(6.0, xs[3].t());
     }
 
+    #[test]
+    fn intersecting_a_world_with_a_bvh_matches_the_flat_fallback() {
+        let mut w = World::default();
+        w.build_bvh();
+        let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 0.0, 1.0));
+
+        let xs = w.intersects(r);
+
+        assert_eq!(4, xs.len());
+        assert_eq!(4.0, xs[0].t());
+        assert_eq!(4.5, xs[1].t());
+        assert_eq!(5.5, xs[2].t());
+        assert_eq!(6.0, xs[3].t());
+    }
+
     #[test]
     fn shading_an_intersection() {
         let w = World::default();
@@ -192,7 +368,7 @@ mod tests {
 
         let comps = PreComputations::new(i, r);
 
-        let c = w.shade_hit(&comps);
+        let c = w.shade_hit(&comps, DEFAULT_REMAINING);
 
         assert_eq!(Color::new(0.38066, 0.47583, 0.2855), c);
     }
@@ -200,7 +376,7 @@ mod tests {
     #[test]
     fn shading_an_intersection_from_the_inside() {
         let mut w = World::default();
-        w.light = Some(PointLight::new(
+        w.set_light(PointLight::new(
             Tuple::point(0.0, 0.25, 0.0),
             Colors::White.into(),
         ));
@@ -210,7 +386,7 @@ mod tests {
 
         let comps = PreComputations::new(i, r);
 
-        let c = w.shade_hit(&comps);
+        let c = w.shade_hit(&comps, DEFAULT_REMAINING);
 
         assert_eq!(Color::new(0.90498, 0.90498, 0.90498), c);
     }
@@ -219,7 +395,7 @@ mod tests {
     fn the_color_when_a_ray_misses() {
         let w = World::default();
         let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 1.0, 0.0));
-        let c = w.color_at(r);
+        let c = w.color_at(r, DEFAULT_REMAINING);
 
         assert_eq!(Color::from(Colors::Black), c);
     }
@@ -228,7 +404,7 @@ mod tests {
     fn the_color_when_a_ray_hits() {
         let w = World::default();
         let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 0.0, 1.0));
-        let c = w.color_at(r);
+        let c = w.color_at(r, DEFAULT_REMAINING);
 
         assert_eq!(Color::new(0.38066, 0.47583, 0.2855), c);
     }
@@ -236,15 +412,15 @@ mod tests {
     #[test]
     fn the_color_with_an_intersection_behind_the_ray() {
         let mut w = World::default();
-        std::rc::Rc::<_>::get_mut(&mut w.shapes_mut().get_mut(0).unwrap())
+        std::sync::Arc::<_>::get_mut(&mut w.shapes_mut().get_mut(0).unwrap())
             .unwrap()
             .set_material(Material::default().with_ambient(1.0));
-        std::rc::Rc::<_>::get_mut(&mut w.shapes_mut().get_mut(1).unwrap())
+        std::sync::Arc::<_>::get_mut(&mut w.shapes_mut().get_mut(1).unwrap())
             .unwrap()
             .set_material(Material::default().with_ambient(1.0));
         let r = Ray::new(Tuple::point(0.0, 0.0, 0.75), Tuple::vector(0.0, 0.0, -1.0));
 
-        let c = w.color_at(r);
+        let c = w.color_at(r, DEFAULT_REMAINING);
         assert_eq!(c, w.shapes()[1].material().pattern().color_at(Tuple::origin()))
     }
 
@@ -253,7 +429,7 @@ mod tests {
         let w = World::default();
         let p = Tuple::point(0.0, 10.0, 0.0);
 
-        assert!(!w.is_shadowed(p));
+        assert!(!w.is_shadowed(p, &w.lights()[0]));
     }
 
     #[test]
@@ -261,7 +437,7 @@ mod tests {
         let w = World::default();
         let p = Tuple::point(10.0, -10.0, 10.0);
 
-        assert!(w.is_shadowed(p));
+        assert!(w.is_shadowed(p, &w.lights()[0]));
     }
 
     #[test]
@@ -269,7 +445,7 @@ mod tests {
         let w = World::default();
         let p = Tuple::point(-20.0, 20.0, -20.0);
 
-        assert!(!w.is_shadowed(p));
+        assert!(!w.is_shadowed(p, &w.lights()[0]));
     }
 
     #[test]
@@ -277,13 +453,13 @@ mod tests {
         let w = World::default();
         let p = Tuple::point(-2.0, 2.0, -2.0);
 
-        assert!(!w.is_shadowed(p));
+        assert!(!w.is_shadowed(p, &w.lights()[0]));
     }
 
     #[test]
     fn shade_hit_is_given_an_intersection_in_shadow() {
         let mut w = World::new();
-        w.light = Some(PointLight::new(Tuple::point(0.0, 0.0, -10.0), Colors::White.into()));
+        w.set_light(PointLight::new(Tuple::point(0.0, 0.0, -10.0), Colors::White.into()));
 
         let s1 = Sphere::new();
         w.add_shape(s1);
@@ -297,8 +473,235 @@ mod tests {
         let i = Intersection::new(4.0, w.shapes()[1].clone());
 
         let comps = PreComputations::new(i, r);
-        let c = w.shade_hit(&comps);
+        let c = w.shade_hit(&comps, DEFAULT_REMAINING);
 
         assert_eq!(Color::new(0.1, 0.1, 0.1), c);
     }
+
+    #[test]
+    fn shading_an_intersection_with_multiple_lights_sums_their_contributions() {
+        let mut w = World::default();
+        let single_light_color = {
+            let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 0.0, 1.0));
+            w.color_at(r, DEFAULT_REMAINING)
+        };
+
+        w.add_light(PointLight::new(
+            Tuple::point(-10.0, 10.0, -10.0),
+            Colors::White.into(),
+        ));
+
+        let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 0.0, 1.0));
+        let c = w.color_at(r, DEFAULT_REMAINING);
+
+        assert_eq!(single_light_color + single_light_color, c);
+    }
+
+    #[test]
+    fn a_surface_can_be_lit_by_one_light_and_shadowed_from_another() {
+        let mut w = World::new();
+        let near_light = PointLight::new(Tuple::point(0.0, 0.0, -10.0), Colors::White.into());
+        let far_light = PointLight::new(Tuple::point(0.0, 0.0, 20.0), Colors::White.into());
+        w.add_light(near_light);
+        w.add_light(far_light);
+
+        let s1 = Sphere::new();
+        w.add_shape(s1);
+
+        let mut s2 = Sphere::new();
+        s2.set_transformation(Transformation::identity().translation(0.0, 0.0, 10.0));
+        w.add_shape(s2);
+
+        let p = Tuple::point(0.0, 0.0, 5.0);
+
+        assert!(!w.is_shadowed(p, &near_light));
+        assert!(w.is_shadowed(p, &far_light));
+    }
+
+    #[test]
+    fn a_single_sample_area_light_reproduces_hard_shadow_behavior() {
+        let mut w = World::new();
+
+        let point_light = PointLight::new(Tuple::point(0.0, 0.0, -10.0), Colors::White.into());
+        let area_light = AreaLight::new(
+            Tuple::point(0.0, 0.0, -10.0),
+            Tuple::vector(1.0, 0.0, 0.0),
+            1,
+            Tuple::vector(0.0, 1.0, 0.0),
+            1,
+            Colors::White.into(),
+        );
+
+        let s1 = Sphere::new();
+        w.add_shape(s1);
+        let mut s2 = Sphere::new();
+        s2.set_transformation(Transformation::identity().translation(0.0, 0.0, 10.0));
+        w.add_shape(s2);
+
+        let lit = Tuple::point(0.0, 0.0, 5.0);
+        let shadowed = Tuple::point(0.0, 0.0, -15.0);
+
+        assert_eq!(
+            !w.is_shadowed(lit, &point_light) as u8 as f64,
+            w.intensity_at(lit, &area_light)
+        );
+        assert_eq!(
+            !w.is_shadowed(shadowed, &point_light) as u8 as f64,
+            w.intensity_at(shadowed, &area_light)
+        );
+    }
+
+    #[test]
+    fn intensity_at_is_a_fraction_for_a_multi_sample_area_light() {
+        let mut w = World::default();
+
+        let area_light = AreaLight::new(
+            Tuple::point(-0.5, 1.0, -5.0),
+            Tuple::vector(1.0, 0.0, 0.0),
+            2,
+            Tuple::vector(0.0, 1.0, 0.0),
+            2,
+            Colors::White.into(),
+        );
+
+        let intensity = w.intensity_at(Tuple::point(0.0, 0.0, 0.0), &area_light);
+
+        assert!((0.0..=1.0).contains(&intensity));
+    }
+
+    #[test]
+    fn the_reflected_color_for_a_nonreflective_material() {
+        let mut w = World::default();
+        let r = Ray::new(Tuple::point(0.0, 0.0, 0.0), Tuple::vector(0.0, 0.0, 1.0));
+        std::sync::Arc::<_>::get_mut(&mut w.shapes_mut().get_mut(1).unwrap())
+            .unwrap()
+            .set_material(Material::default().with_ambient(1.0));
+
+        let shape = w.shapes()[1].clone();
+        let i = Intersection::new(1.0, shape);
+        let comps = PreComputations::new(i, r);
+
+        let color = w.reflected_color(&comps, DEFAULT_REMAINING);
+
+        assert_eq!(Color::from(Colors::Black), color);
+    }
+
+    #[test]
+    fn the_reflected_color_for_a_reflective_material() {
+        let mut w = World::default();
+
+        let mut plane = Sphere::new();
+        plane.set_material(Material::new().with_reflective(0.5));
+        plane.set_transformation(Transformation::identity().translation(0.0, -1.0, 0.0));
+        w.add_shape(plane);
+
+        let r = Ray::new(
+            Tuple::point(0.0, 0.0, -3.0),
+            Tuple::vector(0.0, -2f64.sqrt() / 2.0, 2f64.sqrt() / 2.0),
+        );
+        let shape = w.shapes()[2].clone();
+        let i = Intersection::new(2f64.sqrt(), shape);
+        let comps = PreComputations::new(i, r);
+
+        let color = w.reflected_color(&comps, DEFAULT_REMAINING);
+
+        assert_eq!(Color::new(0.19032, 0.2379, 0.14274), color);
+    }
+
+    #[test]
+    fn shade_hit_with_a_reflective_material() {
+        let mut w = World::default();
+
+        let mut plane = Sphere::new();
+        plane.set_material(Material::new().with_reflective(0.5));
+        plane.set_transformation(Transformation::identity().translation(0.0, -1.0, 0.0));
+        w.add_shape(plane);
+
+        let r = Ray::new(
+            Tuple::point(0.0, 0.0, -3.0),
+            Tuple::vector(0.0, -2f64.sqrt() / 2.0, 2f64.sqrt() / 2.0),
+        );
+        let shape = w.shapes()[2].clone();
+        let i = Intersection::new(2f64.sqrt(), shape);
+        let comps = PreComputations::new(i, r);
+
+        let color = w.shade_hit(&comps, DEFAULT_REMAINING);
+
+        assert_eq!(Color::new(0.87677, 0.92436, 0.82918), color);
+    }
+
+    #[test]
+    fn reflected_color_at_the_maximum_recursive_depth_is_black() {
+        let mut w = World::default();
+
+        let mut plane = Sphere::new();
+        plane.set_material(Material::new().with_reflective(0.5));
+        plane.set_transformation(Transformation::identity().translation(0.0, -1.0, 0.0));
+        w.add_shape(plane);
+
+        let r = Ray::new(
+            Tuple::point(0.0, 0.0, -3.0),
+            Tuple::vector(0.0, -2f64.sqrt() / 2.0, 2f64.sqrt() / 2.0),
+        );
+        let shape = w.shapes()[2].clone();
+        let i = Intersection::new(2f64.sqrt(), shape);
+        let comps = PreComputations::new(i, r);
+
+        let color = w.reflected_color(&comps, 0);
+
+        assert_eq!(Color::from(Colors::Black), color);
+    }
+
+    #[test]
+    fn path_color_is_black_when_the_ray_misses() {
+        let w = World::default();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 1.0, 0.0));
+
+        let c = w.path_color(r, 0, &mut rng);
+
+        assert_eq!(Color::from(Colors::Black), c);
+    }
+
+    #[test]
+    fn path_color_is_reproducible_for_a_seeded_rng() {
+        let w = World::default();
+        let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 0.0, 1.0));
+
+        let mut rng_a = rand::rngs::StdRng::seed_from_u64(42);
+        let mut rng_b = rand::rngs::StdRng::seed_from_u64(42);
+
+        assert_eq!(
+            w.path_color(r, 0, &mut rng_a),
+            w.path_color(r, 0, &mut rng_b)
+        );
+    }
+
+    #[test]
+    fn render_path_traced_averages_its_samples() {
+        let w = World::default();
+        let r = Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 0.0, 1.0));
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+
+        let c = w.render_path_traced(r, 8, &mut rng);
+
+        assert!(c.red() >= 0.0 && c.green() >= 0.0 && c.blue() >= 0.0);
+    }
+
+    #[test]
+    fn render_computes_every_pixel() {
+        let w = World::default();
+        let pixels: std::sync::Mutex<Vec<(usize, usize)>> = std::sync::Mutex::new(vec![]);
+
+        w.render(
+            2,
+            2,
+            |_, _| Ray::new(Tuple::point(0.0, 0.0, -5.0), Tuple::vector(0.0, 0.0, 1.0)),
+            |x, y, _| pixels.lock().unwrap().push((x, y)),
+        );
+
+        let mut seen = pixels.into_inner().unwrap();
+        seen.sort();
+        assert_eq!(vec![(0, 0), (0, 1), (1, 0), (1, 1)], seen);
+    }
 }